@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::collections::{HashMap, HashSet};
+
+use usvg::NodeExt;
+
+/// Returns sorted, unique depths of all colors used by paths.
+///
+/// Each fill and stroke color is mapped to a depth using `depth_map`.
+/// Colors without a mapping are skipped with a warning.
+/// Gradients and patterns are ignored.
+pub fn operation_depths(tree: &usvg::Tree, depth_map: &HashMap<usvg::Color, f64>) -> Vec<f64> {
+    let mut colors = HashSet::new();
+    for node in tree.root.descendants() {
+        if let usvg::NodeKind::Path(ref path) = *node.borrow() {
+            let fill = path.fill.as_ref().map(|f| &f.paint);
+            let stroke = path.stroke.as_ref().map(|s| &s.paint);
+            for paint in fill.into_iter().chain(stroke) {
+                if let usvg::Paint::Color(c) = *paint {
+                    colors.insert(c);
+                }
+            }
+        }
+    }
+
+    let mut depths = Vec::new();
+    for color in colors {
+        match depth_map.get(&color) {
+            Some(depth) => depths.push(*depth),
+            None => log::warn!("Color {:?} doesn't have a depth. Skipped.", color),
+        }
+    }
+
+    depths.sort_by(|a, b| a.total_cmp(b));
+    depths.dedup();
+    depths
+}
+
+/// Returns sorted, unique stroke widths of all stroked paths in millimeters.
+///
+/// Widths include the viewbox and node transforms and are rounded to micrometers.
+/// Pixels are converted into millimeters using `dpi`, which should be the same
+/// as `usvg::Options::dpi` used during parsing.
+pub fn used_stroke_widths_mm(tree: &usvg::Tree, dpi: f32) -> Vec<f64> {
+    let view_box_ts =
+        usvg::utils::view_box_to_transform(tree.view_box.rect, tree.view_box.aspect, tree.size);
+
+    let mut widths = Vec::new();
+    for node in tree.root.descendants() {
+        if let usvg::NodeKind::Path(ref path) = *node.borrow() {
+            if let Some(ref stroke) = path.stroke {
+                let (sx, sy) = view_box_ts.pre_concat(node.abs_transform()).get_scale();
+                let scale = (sx as f64 * sy as f64).sqrt();
+                let width_mm = stroke.width.get() as f64 * scale * 25.4 / dpi as f64;
+                widths.push((width_mm * 1000.0).round() / 1000.0);
+            }
+        }
+    }
+
+    widths.sort_by(|a, b| a.total_cmp(b));
+    widths.dedup();
+    widths
+}
+
+// Relative weights used by `node_render_costs`.
+const LAYER_COST: f64 = 1.0;
+const FILTER_PRIMITIVE_COST: f64 = 4.0;
+
+/// Returns a relative render cost for each top-level node.
+///
+/// The cost is a heuristic based on the number of fills, strokes and images,
+/// the number of layers (groups with opacity, clip paths, masks or filters),
+/// the number of filter primitives and the node's layer bounding box area,
+/// which includes strokes and filter regions.
+/// Costs are only comparable with each other.
+///
+/// Each entry contains the node's index in `tree.root.children()`, its ID and its cost.
+/// The ID can be empty, for example for groups created by `usvg`,
+/// so use the index to identify nodes.
+pub fn node_render_costs(tree: &usvg::Tree) -> Vec<(usize, String, f64)> {
+    let mut costs = Vec::new();
+    for (index, node) in tree.root.children().enumerate() {
+        // Use the layer bbox, which includes strokes and filter regions.
+        // Nodes without one still have to be rendered, so the area is never zero.
+        let area = crate::tree::convert_node(node.clone())
+            .1
+            .map(|r| r.width() as f64 * r.height() as f64)
+            .unwrap_or(0.0)
+            .max(1.0);
+
+        let mut weight = 0.0;
+        for child in node.descendants() {
+            weight += match *child.borrow() {
+                usvg::NodeKind::Path(ref path) => {
+                    let fill = if path.fill.is_some() { 1.0 } else { 0.0 };
+                    let stroke = if path.stroke.is_some() { 1.0 } else { 0.0 };
+                    fill + stroke
+                }
+                usvg::NodeKind::Image(_) => 1.0,
+                usvg::NodeKind::Group(ref g) => {
+                    let filter_primitives: usize =
+                        g.filters.iter().map(|f| f.primitives.len()).sum();
+                    let layer = if g.should_isolate() { LAYER_COST } else { 0.0 };
+                    layer + filter_primitives as f64 * FILTER_PRIMITIVE_COST
+                }
+                usvg::NodeKind::Text(_) => 0.0,
+            };
+        }
+
+        costs.push((index, node.id().to_string(), area * weight));
+    }
+
+    costs
+}
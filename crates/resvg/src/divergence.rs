@@ -0,0 +1,89 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use usvg::filter::Kind;
+use usvg::NodeExt;
+
+// Keep in sync with the known differences between resvg and browsers.
+const MIX_BLEND_MODE: &str = "mix-blend-mode";
+const FE_IMAGE: &str = "feImage";
+const FE_DIFFUSE_LIGHTING: &str = "feDiffuseLighting";
+const FE_SPECULAR_LIGHTING: &str = "feSpecularLighting";
+const FE_TURBULENCE: &str = "feTurbulence";
+const FE_CONVOLVE_MATRIX: &str = "feConvolveMatrix";
+
+/// Returns features that `resvg` is known to render differently than browsers.
+///
+/// Each entry has a `node-id: feature-name` format.
+/// Features of a group without an ID are reported using the ID of its only child
+/// and then using the ID of the nearest ancestor. Features that cannot be attributed
+/// to any element with an ID are skipped.
+///
+/// Currently detects non-normal `mix-blend-mode`, `feImage`, lighting filters,
+/// `feTurbulence` and `feConvolveMatrix`.
+pub fn browser_divergent_features(tree: &usvg::Tree) -> Vec<String> {
+    let mut features = Vec::new();
+    let mut visited = Vec::new();
+    collect_features(&tree.root, &mut visited, &mut features);
+    features
+}
+
+fn collect_features(root: &usvg::Node, visited: &mut Vec<usvg::Node>, features: &mut Vec<String>) {
+    for node in root.descendants() {
+        if let usvg::NodeKind::Group(ref g) = *node.borrow() {
+            let id = match feature_node_id(&node) {
+                Some(v) => v,
+                None => continue,
+            };
+
+            if g.blend_mode != usvg::BlendMode::Normal {
+                features.push(format!("{}: {}", id, MIX_BLEND_MODE));
+            }
+
+            for filter in &g.filters {
+                for primitive in &filter.primitives {
+                    let name = match primitive.kind {
+                        Kind::Image(_) => FE_IMAGE,
+                        Kind::DiffuseLighting(_) => FE_DIFFUSE_LIGHTING,
+                        Kind::SpecularLighting(_) => FE_SPECULAR_LIGHTING,
+                        Kind::Turbulence(_) => FE_TURBULENCE,
+                        Kind::ConvolveMatrix(_) => FE_CONVOLVE_MATRIX,
+                        _ => continue,
+                    };
+
+                    features.push(format!("{}: {}", id, name));
+                }
+            }
+        }
+
+        // Clip paths, masks and patterns can be shared between elements.
+        // Process them only once.
+        node.subroots(|subroot| {
+            if !visited.contains(&subroot) {
+                visited.push(subroot.clone());
+                collect_features(&subroot, visited, features);
+            }
+        });
+    }
+}
+
+fn feature_node_id(node: &usvg::Node) -> Option<String> {
+    let id = node.id().to_string();
+    if !id.is_empty() {
+        return Some(id);
+    }
+
+    // `usvg` creates unnamed groups for single elements with blend modes or filters.
+    if let (Some(first), Some(last)) = (node.first_child(), node.last_child()) {
+        let child_id = first.id().to_string();
+        if first == last && !child_id.is_empty() {
+            return Some(child_id);
+        }
+    }
+
+    node.ancestors()
+        .skip(1)
+        .map(|n| n.id().to_string())
+        .find(|id| !id.is_empty())
+}
@@ -18,7 +18,9 @@
 pub use tiny_skia;
 pub use usvg;
 
+mod analysis;
 mod clip;
+mod divergence;
 mod filter;
 mod geom;
 mod image;
@@ -26,6 +28,11 @@ mod mask;
 mod paint_server;
 mod path;
 mod render;
+mod stability;
 mod tree;
 
+pub use crate::analysis::{node_render_costs, operation_depths, used_stroke_widths_mm};
+pub use crate::divergence::browser_divergent_features;
+pub use crate::render::render_subpath;
+pub use crate::stability::verify_render_stability;
 pub use crate::tree::Tree;
@@ -25,6 +25,16 @@ pub struct StrokePath {
 }
 
 pub fn convert(upath: &usvg::Path, children: &mut Vec<Node>) -> Option<BBoxes> {
+    convert_with_paint_bbox(upath, None, children)
+}
+
+/// Like `convert`, but resolves `objectBoundingBox` paint servers using `paint_bbox`
+/// instead of the path's own object bbox, when set.
+pub(crate) fn convert_with_paint_bbox(
+    upath: &usvg::Path,
+    paint_bbox: Option<tiny_skia::NonZeroRect>,
+    children: &mut Vec<Node>,
+) -> Option<BBoxes> {
     let transform = upath.transform;
     let anti_alias = upath.rendering_mode.use_shape_antialiasing();
 
@@ -34,6 +44,7 @@ pub fn convert(upath: &usvg::Path, children: &mut Vec<Node>) -> Option<BBoxes> {
             upath.data.clone(),
             transform,
             upath.text_bbox,
+            paint_bbox,
             anti_alias,
         )
     });
@@ -44,6 +55,7 @@ pub fn convert(upath: &usvg::Path, children: &mut Vec<Node>) -> Option<BBoxes> {
             upath.data.clone(),
             transform,
             upath.text_bbox,
+            paint_bbox,
             anti_alias,
         )
     });
@@ -97,6 +109,7 @@ fn convert_fill_path(
     path: Rc<tiny_skia::Path>,
     transform: tiny_skia::Transform,
     text_bbox: Option<tiny_skia::NonZeroRect>,
+    paint_bbox: Option<tiny_skia::NonZeroRect>,
     anti_alias: bool,
 ) -> Option<(FillPath, usvg::BBox, usvg::BBox)> {
     // Horizontal and vertical lines cannot be filled. Skip.
@@ -114,8 +127,11 @@ fn convert_fill_path(
         object_bbox = object_bbox.expand(usvg::BBox::from(text_bbox));
     }
 
-    let paint =
-        crate::paint_server::convert(&ufill.paint, ufill.opacity, object_bbox.to_non_zero_rect())?;
+    let paint = crate::paint_server::convert(
+        &ufill.paint,
+        ufill.opacity,
+        paint_bbox.or_else(|| object_bbox.to_non_zero_rect()),
+    )?;
 
     let path = FillPath {
         transform,
@@ -133,6 +149,7 @@ fn convert_stroke_path(
     path: Rc<tiny_skia::Path>,
     transform: tiny_skia::Transform,
     text_bbox: Option<tiny_skia::NonZeroRect>,
+    paint_bbox: Option<tiny_skia::NonZeroRect>,
     anti_alias: bool,
 ) -> Option<(StrokePath, usvg::BBox, usvg::BBox)> {
     let mut stroke = tiny_skia::Stroke {
@@ -164,7 +181,7 @@ fn convert_stroke_path(
     let paint = crate::paint_server::convert(
         &ustroke.paint,
         ustroke.opacity,
-        complete_object_bbox.to_non_zero_rect(),
+        paint_bbox.or_else(|| complete_object_bbox.to_non_zero_rect()),
     )?;
 
     if let Some(ref list) = ustroke.dasharray {
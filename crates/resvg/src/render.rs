@@ -2,6 +2,12 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use std::rc::Rc;
+
+#[cfg(feature = "svg-image")]
+use base64::Engine;
+use usvg::NodeExt;
+
 use crate::tree::{Group, Node, OptionLog, Tree};
 
 pub struct Context {
@@ -33,6 +39,235 @@ impl Tree {
         let ctx = Context { max_bbox };
         render_nodes(&self.children, &ctx, root_transform, pixmap);
     }
+
+    /// Renders the tree onto the pixmap and draws fiducial marks on top.
+    ///
+    /// Each mark is a cross centered at a position from `fiducials`,
+    /// `size` units wide, with a line width of `size / 10`.
+    /// Positions and `size` are in document coordinates, i.e. the marks
+    /// use the same viewbox and root `transform` as the tree itself.
+    pub fn render_with_fiducials(
+        &self,
+        fiducials: &[(f64, f64)],
+        size: f64,
+        color: tiny_skia::Color,
+        transform: tiny_skia::Transform,
+        pixmap: &mut tiny_skia::PixmapMut,
+    ) {
+        self.render(transform, pixmap);
+
+        let half = size as f32 / 2.0;
+        let mut builder = tiny_skia::PathBuilder::new();
+        for &(x, y) in fiducials {
+            let (x, y) = (x as f32, y as f32);
+            builder.move_to(x - half, y);
+            builder.line_to(x + half, y);
+            builder.move_to(x, y - half);
+            builder.line_to(x, y + half);
+        }
+
+        let path = match builder.finish() {
+            Some(v) => v,
+            None => return,
+        };
+
+        let mut paint = tiny_skia::Paint::default();
+        paint.set_color(color);
+
+        let stroke = tiny_skia::Stroke {
+            width: size as f32 / 10.0,
+            ..tiny_skia::Stroke::default()
+        };
+
+        let ts =
+            usvg::utils::view_box_to_transform(self.view_box.rect, self.view_box.aspect, self.size);
+        pixmap.stroke_path(&path, &paint, &stroke, transform.pre_concat(ts), None);
+    }
+
+    /// Renders the tree into an SVG with a single embedded PNG image.
+    ///
+    /// Useful for consumers that accept only SVG.
+    /// Requires the `svg-image` feature.
+    /// The output SVG and the image have a `width`x`height` size.
+    ///
+    /// `transform` will be used as a root transform, just like in [`Tree::render`].
+    ///
+    /// # Panics
+    ///
+    /// When `width` or `height` is zero.
+    #[cfg(feature = "svg-image")]
+    pub fn render_to_svg_image(
+        &self,
+        width: u32,
+        height: u32,
+        transform: tiny_skia::Transform,
+    ) -> String {
+        let mut pixmap = tiny_skia::Pixmap::new(width, height).unwrap();
+        self.render(transform, &mut pixmap.as_mut());
+
+        let png_data = pixmap.encode_png().unwrap();
+        let data = base64::engine::general_purpose::STANDARD.encode(png_data);
+
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+             xmlns:xlink=\"http://www.w3.org/1999/xlink\" \
+             width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\
+             <image width=\"{w}\" height=\"{h}\" xlink:href=\"data:image/png;base64,{data}\"/>\
+             </svg>",
+            w = width,
+            h = height,
+            data = data,
+        )
+    }
+
+    /// Renders the tree into a new pixmap using supersampling.
+    ///
+    /// The tree is rendered at `factor` times the target resolution
+    /// and then downscaled to `width`x`height` using a box filter.
+    /// Improves thin and high-contrast details at the cost of
+    /// `factor * factor` more memory and render time.
+    ///
+    /// `transform` will be used as a root transform, just like in [`Tree::render`].
+    ///
+    /// # Panics
+    ///
+    /// - When `factor` is not in a 1..=4 range.
+    /// - When `width * factor` or `height * factor` overflows `u32`.
+    /// - When `width` or `height` is zero.
+    pub fn render_supersampled(
+        &self,
+        width: u32,
+        height: u32,
+        factor: u32,
+        transform: tiny_skia::Transform,
+    ) -> tiny_skia::Pixmap {
+        assert!((1..=4).contains(&factor), "factor must be in a 1..=4 range");
+
+        let big_width = width
+            .checked_mul(factor)
+            .expect("supersampled width overflows");
+        let big_height = height
+            .checked_mul(factor)
+            .expect("supersampled height overflows");
+
+        let mut pixmap = tiny_skia::Pixmap::new(big_width, big_height).unwrap();
+        let ts = tiny_skia::Transform::from_scale(factor as f32, factor as f32);
+        self.render(ts.pre_concat(transform), &mut pixmap.as_mut());
+
+        downscale(&pixmap, factor)
+    }
+}
+
+/// Renders a single subpath of a path node onto the pixmap.
+///
+/// Only the subpath itself is rendered, using the node's fill and stroke
+/// and its absolute transform. Paint servers are still resolved using the whole path.
+/// Ancestor groups opacity, clip paths, masks and filters are ignored.
+/// Mainly useful for debugging.
+///
+/// `transform` will be used as a root transform, just like in [`Tree::render`].
+///
+/// Returns `None` when `node_id` doesn't reference a path
+/// or when `subpath_index` is out of bounds.
+pub fn render_subpath(
+    tree: &usvg::Tree,
+    node_id: &str,
+    subpath_index: usize,
+    transform: tiny_skia::Transform,
+    pixmap: &mut tiny_skia::PixmapMut,
+) -> Option<()> {
+    let node = tree.node_by_id(node_id)?;
+    let mut path = match *node.borrow() {
+        usvg::NodeKind::Path(ref path) => path.clone(),
+        _ => return None,
+    };
+
+    let subpath = split_subpaths(&path.data).into_iter().nth(subpath_index)?;
+
+    // Make sure `objectBoundingBox` paint servers are still resolved
+    // using the whole path and not just the subpath.
+    let mut paint_bbox = usvg::BBox::from(path.data.bounds());
+    if let Some(text_bbox) = path.text_bbox {
+        paint_bbox = paint_bbox.expand(usvg::BBox::from(text_bbox));
+    }
+
+    path.data = Rc::new(subpath);
+    path.transform = node.abs_transform();
+
+    // A path without a visible fill or stroke is not an error. Just render nothing.
+    let mut children = Vec::new();
+    let bboxes =
+        crate::path::convert_with_paint_bbox(&path, paint_bbox.to_non_zero_rect(), &mut children);
+
+    let rtree = Tree {
+        size: tree.size,
+        view_box: tree.view_box,
+        content_area: bboxes.and_then(|b| b.layer.to_rect()),
+        children,
+    };
+
+    rtree.render(transform, pixmap);
+    Some(())
+}
+
+fn downscale(pixmap: &tiny_skia::Pixmap, factor: u32) -> tiny_skia::Pixmap {
+    let width = pixmap.width() / factor;
+    let height = pixmap.height() / factor;
+    let mut small = tiny_skia::Pixmap::new(width, height).unwrap();
+
+    let samples = factor * factor;
+    let src = pixmap.data();
+    let dst = small.data_mut();
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = [0u32; 4];
+            for sy in y * factor..(y + 1) * factor {
+                for sx in x * factor..(x + 1) * factor {
+                    let idx = ((sy * pixmap.width() + sx) * 4) as usize;
+                    for (c, s) in sum.iter_mut().enumerate() {
+                        *s += src[idx + c] as u32;
+                    }
+                }
+            }
+
+            let idx = ((y * width + x) * 4) as usize;
+            for (c, s) in sum.iter().enumerate() {
+                // Averaging premultiplied values keeps them premultiplied.
+                dst[idx + c] = ((s + samples / 2) / samples) as u8;
+            }
+        }
+    }
+
+    small
+}
+
+fn split_subpaths(path: &tiny_skia::Path) -> Vec<tiny_skia::Path> {
+    let mut subpaths = Vec::new();
+    let mut builder = tiny_skia::PathBuilder::new();
+    for segment in path.segments() {
+        match segment {
+            tiny_skia::PathSegment::MoveTo(p) => {
+                let prev = std::mem::replace(&mut builder, tiny_skia::PathBuilder::new());
+                if let Some(subpath) = prev.finish() {
+                    subpaths.push(subpath);
+                }
+
+                builder.move_to(p.x, p.y);
+            }
+            tiny_skia::PathSegment::LineTo(p) => builder.line_to(p.x, p.y),
+            tiny_skia::PathSegment::QuadTo(p1, p) => builder.quad_to(p1.x, p1.y, p.x, p.y),
+            tiny_skia::PathSegment::CubicTo(p1, p2, p) => {
+                builder.cubic_to(p1.x, p1.y, p2.x, p2.y, p.x, p.y)
+            }
+            tiny_skia::PathSegment::Close => builder.close(),
+        }
+    }
+
+    if let Some(subpath) = builder.finish() {
+        subpaths.push(subpath);
+    }
+
+    subpaths
 }
 
 pub fn render_nodes(
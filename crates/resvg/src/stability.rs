@@ -0,0 +1,75 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use usvg::{TreeParsing, TreeWriting};
+
+use crate::tree::Tree;
+
+/// Checks that an SVG renders the same after a `usvg` serialization round-trip.
+///
+/// Parses `svg_str`, writes the result back to SVG using [`usvg::TreeWriting`],
+/// parses it again and compares renderings of both trees at `width`x`height`.
+///
+/// Both trees must have the same size and are rendered using the same transform.
+/// Pixels are allowed to differ by 1 per channel to account for rounding.
+///
+/// Text nodes are not converted into paths and will be ignored.
+///
+/// Returns `false` when the SVG cannot be parsed or rendered.
+pub fn verify_render_stability(svg_str: &str, width: u32, height: u32) -> bool {
+    let opt = usvg::Options::default();
+
+    let tree = match usvg::Tree::from_str(svg_str, &opt) {
+        Ok(v) => v,
+        Err(e) => {
+            log::warn!("Failed to parse an SVG: {}.", e);
+            return false;
+        }
+    };
+
+    let svg_str2 = tree.to_string(&usvg::XmlOptions::default());
+    let tree2 = match usvg::Tree::from_str(&svg_str2, &opt) {
+        Ok(v) => v,
+        Err(e) => {
+            log::warn!("Failed to parse a serialized SVG: {}.", e);
+            return false;
+        }
+    };
+
+    if tree.size != tree2.size {
+        return false;
+    }
+
+    // Use the same transform for both trees, so content scaling would not be masked.
+    let ts = tiny_skia::Transform::from_scale(
+        width as f32 / tree.size.width(),
+        height as f32 / tree.size.height(),
+    );
+
+    let pixmap1 = render(&tree, width, height, ts);
+    let pixmap2 = render(&tree2, width, height, ts);
+    match (pixmap1, pixmap2) {
+        (Some(pixmap1), Some(pixmap2)) => !is_pixmap_diff(&pixmap1, &pixmap2),
+        _ => false,
+    }
+}
+
+fn render(
+    tree: &usvg::Tree,
+    width: u32,
+    height: u32,
+    transform: tiny_skia::Transform,
+) -> Option<tiny_skia::Pixmap> {
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+    Tree::from_usvg(tree).render(transform, &mut pixmap.as_mut());
+    Some(pixmap)
+}
+
+fn is_pixmap_diff(pixmap1: &tiny_skia::Pixmap, pixmap2: &tiny_skia::Pixmap) -> bool {
+    pixmap1
+        .data()
+        .iter()
+        .zip(pixmap2.data())
+        .any(|(a, b)| (*a as i32 - *b as i32).abs() > 1)
+}
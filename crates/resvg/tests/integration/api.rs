@@ -0,0 +1,362 @@
+use usvg::TreeParsing;
+
+fn parse(svg: &str) -> usvg::Tree {
+    usvg::Tree::from_str(svg, &usvg::Options::default()).unwrap()
+}
+
+#[test]
+fn render_subpath() {
+    let tree = parse(
+        "
+    <svg width='100' height='100' xmlns='http://www.w3.org/2000/svg'>
+        <path id='path1' d='M 10 10 H 40 V 40 H 10 Z M 60 60 H 90 V 90 H 60 Z'/>
+    </svg>
+    ",
+    );
+
+    let mut pixmap = tiny_skia::Pixmap::new(100, 100).unwrap();
+    let ts = tiny_skia::Transform::default();
+    assert!(resvg::render_subpath(&tree, "path1", 1, ts, &mut pixmap.as_mut()).is_some());
+    assert_eq!(pixmap.pixel(25, 25).unwrap().alpha(), 0);
+    assert_eq!(pixmap.pixel(75, 75).unwrap().alpha(), 255);
+
+    assert!(resvg::render_subpath(&tree, "path1", 2, ts, &mut pixmap.as_mut()).is_none());
+    assert!(resvg::render_subpath(&tree, "path2", 0, ts, &mut pixmap.as_mut()).is_none());
+}
+
+#[test]
+fn render_subpath_with_gradient() {
+    let tree = parse(
+        "
+    <svg width='100' height='100' xmlns='http://www.w3.org/2000/svg'>
+        <linearGradient id='lg'>
+            <stop offset='0' stop-color='red'/>
+            <stop offset='1' stop-color='blue'/>
+        </linearGradient>
+        <path id='path1' d='M 10 10 H 40 V 40 H 10 Z M 60 60 H 90 V 90 H 60 Z' fill='url(#lg)'/>
+    </svg>
+    ",
+    );
+
+    let ts = tiny_skia::Transform::default();
+
+    let mut full = tiny_skia::Pixmap::new(100, 100).unwrap();
+    resvg::Tree::from_usvg(&tree).render(ts, &mut full.as_mut());
+
+    let mut subpath = tiny_skia::Pixmap::new(100, 100).unwrap();
+    assert!(resvg::render_subpath(&tree, "path1", 1, ts, &mut subpath.as_mut()).is_some());
+
+    // The gradient must be resolved using the whole path bbox.
+    assert_eq!(full.pixel(65, 75), subpath.pixel(65, 75));
+    assert_eq!(full.pixel(85, 75), subpath.pixel(85, 75));
+}
+
+#[test]
+fn render_stability() {
+    let names = [
+        "simple-stroke",
+        "fill-and-stroke",
+        "transformed-rect",
+        "group-with-only-transform",
+        "paint-order=stroke",
+        "stroke-linecap=square",
+    ];
+
+    for name in names {
+        let svg = std::fs::read_to_string(format!("tests/extra/{}.svg", name)).unwrap();
+        assert!(resvg::verify_render_stability(&svg, 200, 200), "{}", name);
+    }
+}
+
+#[test]
+fn render_stability_of_invalid_svg() {
+    assert!(!resvg::verify_render_stability("<svg", 200, 200));
+}
+
+#[test]
+fn browser_divergent_features() {
+    let tree = parse(
+        "
+    <svg width='100' height='100' xmlns='http://www.w3.org/2000/svg'>
+        <rect id='rect1' width='50' height='50'/>
+        <rect id='rect2' width='50' height='50' style='mix-blend-mode:multiply'/>
+    </svg>
+    ",
+    );
+
+    let features = resvg::browser_divergent_features(&tree);
+    assert_eq!(features, vec!["rect2: mix-blend-mode".to_string()]);
+}
+
+#[test]
+fn browser_divergent_features_of_unnamed_group() {
+    let tree = parse(
+        "
+    <svg width='100' height='100' xmlns='http://www.w3.org/2000/svg'>
+        <g id='layer1'>
+            <g style='mix-blend-mode:multiply'>
+                <rect id='rect1' width='50' height='50'/>
+                <rect id='rect2' x='50' width='50' height='50'/>
+            </g>
+        </g>
+        <g style='mix-blend-mode:screen'>
+            <rect id='rect3' width='50' height='50'/>
+            <rect id='rect4' x='50' width='50' height='50'/>
+        </g>
+    </svg>
+    ",
+    );
+
+    // The second group cannot be attributed to any element and is skipped.
+    let features = resvg::browser_divergent_features(&tree);
+    assert_eq!(features, vec!["layer1: mix-blend-mode".to_string()]);
+}
+
+#[test]
+fn browser_divergent_features_in_shared_mask() {
+    let tree = parse(
+        "
+    <svg width='100' height='100' xmlns='http://www.w3.org/2000/svg'>
+        <mask id='mask1'>
+            <rect id='rect1' width='50' height='50' fill='white'
+                  style='mix-blend-mode:screen'/>
+        </mask>
+        <rect id='rect2' width='50' height='50' mask='url(#mask1)'/>
+        <rect id='rect3' x='50' width='50' height='50' mask='url(#mask1)'/>
+    </svg>
+    ",
+    );
+
+    let features = resvg::browser_divergent_features(&tree);
+    assert_eq!(features, vec!["rect1: mix-blend-mode".to_string()]);
+}
+
+#[test]
+fn operation_depths() {
+    let tree = parse(
+        "
+    <svg width='100' height='100' xmlns='http://www.w3.org/2000/svg'>
+        <rect width='10' height='10' fill='blue'/>
+        <rect width='10' height='10' fill='none' stroke='red'/>
+        <rect width='10' height='10' fill='red' stroke='blue'/>
+        <rect width='10' height='10' fill='green'/>
+    </svg>
+    ",
+    );
+
+    let mut depth_map = std::collections::HashMap::new();
+    depth_map.insert(usvg::Color::new_rgb(0, 0, 255), 1.0);
+    depth_map.insert(usvg::Color::new_rgb(255, 0, 0), 3.0);
+
+    assert_eq!(resvg::operation_depths(&tree, &depth_map), vec![1.0, 3.0]);
+}
+
+#[test]
+fn render_supersampled() {
+    // Anti-aliasing is disabled, so only supersampling can produce partial coverage.
+    let tree = parse(
+        "
+    <svg width='100' height='100' xmlns='http://www.w3.org/2000/svg'>
+        <path d='M 0 10 L 100 90' stroke='black' shape-rendering='crispEdges'/>
+    </svg>
+    ",
+    );
+    let rtree = resvg::Tree::from_usvg(&tree);
+
+    let partial_pixels = |factor| {
+        let ts = tiny_skia::Transform::default();
+        let pixmap = rtree.render_supersampled(100, 100, factor, ts);
+        assert_eq!((pixmap.width(), pixmap.height()), (100, 100));
+        pixmap
+            .pixels()
+            .iter()
+            .filter(|p| p.alpha() != 0 && p.alpha() != 255)
+            .count()
+    };
+
+    assert_eq!(partial_pixels(1), 0);
+    assert!(partial_pixels(3) > 0);
+}
+
+#[test]
+#[should_panic(expected = "factor must be in a 1..=4 range")]
+fn render_supersampled_invalid_factor() {
+    let tree = parse("<svg width='10' height='10' xmlns='http://www.w3.org/2000/svg'/>");
+    let rtree = resvg::Tree::from_usvg(&tree);
+    rtree.render_supersampled(10, 10, 5, tiny_skia::Transform::default());
+}
+
+#[test]
+#[should_panic(expected = "overflows")]
+fn render_supersampled_size_overflow() {
+    let tree = parse("<svg width='10' height='10' xmlns='http://www.w3.org/2000/svg'/>");
+    let rtree = resvg::Tree::from_usvg(&tree);
+    rtree.render_supersampled((1 << 31) + 1, 1, 2, tiny_skia::Transform::default());
+}
+
+#[test]
+fn node_render_costs() {
+    let tree = parse(
+        "
+    <svg width='100' height='100' xmlns='http://www.w3.org/2000/svg'>
+        <filter id='filter1'>
+            <feGaussianBlur stdDeviation='5'/>
+        </filter>
+        <rect id='rect1' width='50' height='50'/>
+        <rect id='rect2' width='50' height='50' filter='url(#filter1)'/>
+    </svg>
+    ",
+    );
+
+    let costs = resvg::node_render_costs(&tree);
+    assert_eq!(costs.len(), 2);
+    assert_eq!((costs[0].0, costs[0].1.as_str()), (0, "rect1"));
+    assert_eq!(costs[1].0, 1);
+    assert!(costs[0].2 > 0.0);
+    assert!(costs[1].2 > costs[0].2);
+}
+
+#[test]
+fn node_render_costs_with_stroke_and_filter() {
+    let tree = parse(
+        "
+    <svg width='100' height='100' xmlns='http://www.w3.org/2000/svg'>
+        <filter id='filter1' filterUnits='userSpaceOnUse' x='-100' y='0' width='300' height='100'>
+            <feGaussianBlur stdDeviation='5'/>
+            <feGaussianBlur stdDeviation='5'/>
+        </filter>
+        <path id='line1' d='M 0 50 H 100' stroke='black' stroke-width='10'/>
+        <path id='line2' d='M 0 50 H 100' stroke='black' stroke-width='10'
+              filter='url(#filter1)'/>
+        <rect id='rect1' width='100' height='100'/>
+    </svg>
+    ",
+    );
+
+    let costs = resvg::node_render_costs(&tree);
+    assert_eq!(costs.len(), 3);
+    assert_eq!(costs[0].1, "line1");
+    // A zero-height path still has a stroke area.
+    assert!(costs[0].2 >= 1000.0);
+    assert!(costs[1].2 > costs[2].2);
+}
+
+#[test]
+fn node_render_costs_of_unnamed_group() {
+    let tree = parse(
+        "
+    <svg width='100' height='100' xmlns='http://www.w3.org/2000/svg'>
+        <g>
+            <rect id='first' width='1' height='1'/>
+            <rect id='big' width='100' height='100'/>
+        </g>
+        <g opacity='0.5'>
+            <rect id='single' width='1' height='1'/>
+        </g>
+        <g id='group1' opacity='0.5'>
+            <rect id='rect1' width='1' height='1'/>
+        </g>
+    </svg>
+    ",
+    );
+
+    // Groups are not named after their children.
+    let costs = resvg::node_render_costs(&tree);
+    let keys: Vec<_> = costs.iter().map(|c| (c.0, c.1.as_str())).collect();
+    assert_eq!(keys, vec![(0, ""), (1, ""), (2, "group1")]);
+}
+
+#[test]
+fn render_with_fiducials() {
+    let tree = parse("<svg width='100' height='100' xmlns='http://www.w3.org/2000/svg'/>");
+    let rtree = resvg::Tree::from_usvg(&tree);
+
+    let mut pixmap = tiny_skia::Pixmap::new(200, 200).unwrap();
+    rtree.render_with_fiducials(
+        &[(10.0, 10.0), (90.0, 90.0)],
+        10.0,
+        tiny_skia::Color::from_rgba8(255, 0, 0, 255),
+        tiny_skia::Transform::from_scale(2.0, 2.0),
+        &mut pixmap.as_mut(),
+    );
+
+    for (x, y) in [(20, 20), (180, 180), (10, 20), (20, 10)] {
+        let pixel = pixmap.pixel(x, y).unwrap();
+        assert_eq!((pixel.red(), pixel.alpha()), (255, 255), "{}x{}", x, y);
+    }
+
+    assert_eq!(pixmap.pixel(100, 100).unwrap().alpha(), 0);
+    assert_eq!(pixmap.pixel(40, 40).unwrap().alpha(), 0);
+}
+
+#[test]
+fn used_stroke_widths_mm() {
+    let tree = parse(
+        "
+    <svg width='100mm' height='100mm' viewBox='0 0 100 100'
+         xmlns='http://www.w3.org/2000/svg'>
+        <path d='M 10 10 L 90 10' stroke='black' stroke-width='0.5'/>
+        <path d='M 10 20 L 90 20' stroke='black' stroke-width='2'/>
+        <path d='M 10 30 L 90 30' stroke='red' stroke-width='0.5'/>
+        <g transform='scale(2)'>
+            <path d='M 10 20 L 40 20' stroke='black' stroke-width='1'/>
+        </g>
+        <rect width='10' height='10'/>
+    </svg>
+    ",
+    );
+
+    assert_eq!(resvg::used_stroke_widths_mm(&tree, 96.0), vec![0.5, 2.0]);
+}
+
+#[test]
+fn used_stroke_widths_mm_with_custom_dpi() {
+    let opt = usvg::Options {
+        dpi: 300.0,
+        ..usvg::Options::default()
+    };
+
+    let tree = usvg::Tree::from_str(
+        "
+    <svg width='100mm' height='100mm' xmlns='http://www.w3.org/2000/svg'>
+        <path d='M 10 10 L 90 10' stroke='black' stroke-width='0.5mm'/>
+        <path d='M 10 20 L 90 20' stroke='black' stroke-width='2mm'/>
+    </svg>
+    ",
+        &opt,
+    )
+    .unwrap();
+
+    assert_eq!(resvg::used_stroke_widths_mm(&tree, opt.dpi), vec![0.5, 2.0]);
+}
+
+#[cfg(feature = "svg-image")]
+#[test]
+fn render_to_svg_image() {
+    let tree = parse(
+        "
+    <svg width='20' height='10' xmlns='http://www.w3.org/2000/svg'>
+        <rect width='10' height='10' fill='green'/>
+    </svg>
+    ",
+    );
+    let rtree = resvg::Tree::from_usvg(&tree);
+
+    let svg = rtree.render_to_svg_image(20, 10, tiny_skia::Transform::default());
+    assert!(svg.contains("data:image/png;base64,"));
+
+    let tree = parse(&svg);
+    assert_eq!(tree.size, usvg::Size::from_wh(20.0, 10.0).unwrap());
+
+    let node = tree.root.first_child().unwrap();
+    match *node.borrow() {
+        usvg::NodeKind::Image(ref image) => {
+            assert!(matches!(image.kind, usvg::ImageKind::PNG(_)));
+            assert_eq!(
+                image.view_box.rect,
+                usvg::NonZeroRect::from_xywh(0.0, 0.0, 20.0, 10.0).unwrap()
+            );
+        }
+        _ => panic!("expected an image"),
+    };
+}
@@ -5,6 +5,7 @@ use usvg::{fontdb, TreeParsing, TreeTextToPath};
 #[rustfmt::skip]
 mod render;
 
+mod api;
 mod extra;
 
 const IMAGE_SIZE: u32 = 300;
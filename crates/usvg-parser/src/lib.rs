@@ -170,6 +170,42 @@ pub fn decompress_svgz(data: &[u8]) -> Result<Vec<u8>, Error> {
     Ok(decoded)
 }
 
+/// Returns Inkscape labels of all elements that have an ID.
+///
+/// Inkscape stores human-readable layer and object names in the `inkscape:label`
+/// attribute, which is not preserved by `usvg`.
+///
+/// The key is an element ID and the value is its label.
+/// Returns an empty map when the SVG cannot be parsed.
+pub fn layer_labels(svg_str: &str) -> std::collections::HashMap<String, String> {
+    const INKSCAPE_NS: &str = "http://www.inkscape.org/namespaces/inkscape";
+
+    let mut labels = std::collections::HashMap::new();
+
+    let xml_opt = roxmltree::ParsingOptions {
+        allow_dtd: true,
+        ..Default::default()
+    };
+
+    let doc = match roxmltree::Document::parse_with_options(svg_str, xml_opt) {
+        Ok(v) => v,
+        Err(e) => {
+            log::warn!("Failed to parse an SVG: {}.", e);
+            return labels;
+        }
+    };
+
+    for node in doc.descendants() {
+        if let (Some(id), Some(label)) =
+            (node.attribute("id"), node.attribute((INKSCAPE_NS, "label")))
+        {
+            labels.insert(id.to_string(), label.to_string());
+        }
+    }
+
+    labels
+}
+
 #[inline]
 pub(crate) fn f32_bound(min: f32, val: f32, max: f32) -> f32 {
     debug_assert!(min.is_finite());
@@ -6,6 +6,24 @@ use usvg_tree::{ImageRendering, ShapeRendering, Size, TextRendering};
 
 use crate::ImageHrefResolver;
 
+/// A rendering quality preset.
+///
+/// Sets [`Options::shape_rendering`], [`Options::text_rendering`]
+/// and [`Options::image_rendering`] at once.
+/// Use [`Options::set_render_quality`] to apply it.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub enum RenderQuality {
+    /// No anti-aliasing and nearest-neighbor image scaling.
+    Draft,
+    /// Default rendering methods.
+    #[default]
+    Normal,
+    /// The same as `Normal`, but text uses `TextRendering::GeometricPrecision`.
+    ///
+    /// Shapes and images are already rendered with the highest quality by `Normal`.
+    High,
+}
+
 /// Processing options.
 #[derive(Debug)]
 pub struct Options {
@@ -99,6 +117,33 @@ impl Default for Options {
 }
 
 impl Options {
+    /// Sets default rendering methods using a quality preset.
+    ///
+    /// Individual rendering methods can still be changed afterwards.
+    pub fn set_render_quality(&mut self, quality: RenderQuality) {
+        let (shape, text, image) = match quality {
+            RenderQuality::Draft => (
+                ShapeRendering::OptimizeSpeed,
+                TextRendering::OptimizeSpeed,
+                ImageRendering::OptimizeSpeed,
+            ),
+            RenderQuality::Normal => (
+                ShapeRendering::default(),
+                TextRendering::default(),
+                ImageRendering::default(),
+            ),
+            RenderQuality::High => (
+                ShapeRendering::GeometricPrecision,
+                TextRendering::GeometricPrecision,
+                ImageRendering::OptimizeQuality,
+            ),
+        };
+
+        self.shape_rendering = shape;
+        self.text_rendering = text;
+        self.image_rendering = image;
+    }
+
     /// Converts a relative path into absolute relative to the SVG file itself.
     ///
     /// If `Options::resources_dir` is not set, returns itself.
@@ -86,3 +86,43 @@ fn invalid_size_1() {
     let result = usvg_tree::Tree::from_str(&svg, &usvg_parser::Options::default());
     assert!(result.is_err());
 }
+
+#[test]
+fn render_quality_presets() {
+    use usvg_tree::{ImageRendering, ShapeRendering, TextRendering};
+
+    let mut opt = usvg_parser::Options::default();
+
+    opt.set_render_quality(usvg_parser::RenderQuality::Draft);
+    assert_eq!(opt.shape_rendering, ShapeRendering::OptimizeSpeed);
+    assert_eq!(opt.text_rendering, TextRendering::OptimizeSpeed);
+    assert_eq!(opt.image_rendering, ImageRendering::OptimizeSpeed);
+
+    opt.set_render_quality(usvg_parser::RenderQuality::Normal);
+    assert_eq!(opt.shape_rendering, ShapeRendering::GeometricPrecision);
+    assert_eq!(opt.text_rendering, TextRendering::OptimizeLegibility);
+    assert_eq!(opt.image_rendering, ImageRendering::OptimizeQuality);
+
+    opt.set_render_quality(usvg_parser::RenderQuality::High);
+    assert_eq!(opt.shape_rendering, ShapeRendering::GeometricPrecision);
+    assert_eq!(opt.text_rendering, TextRendering::GeometricPrecision);
+    assert_eq!(opt.image_rendering, ImageRendering::OptimizeQuality);
+}
+
+#[test]
+fn inkscape_layer_labels() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg'
+         xmlns:inkscape='http://www.inkscape.org/namespaces/inkscape'>
+        <g id='layer1' inkscape:groupmode='layer' inkscape:label='Cut'>
+            <rect id='rect1' width='10' height='10'/>
+        </g>
+        <g id='layer2' inkscape:groupmode='layer' inkscape:label='Engrave'/>
+    </svg>
+    ";
+
+    let labels = usvg_parser::layer_labels(svg);
+    assert_eq!(labels.len(), 2);
+    assert_eq!(labels["layer1"], "Cut");
+    assert_eq!(labels["layer2"], "Engrave");
+}
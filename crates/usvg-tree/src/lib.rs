@@ -543,7 +543,7 @@ impl Default for Fill {
 }
 
 /// A 8-bit RGB color.
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 #[allow(missing_docs)]
 pub struct Color {
     pub red: u8,